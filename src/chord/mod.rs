@@ -11,6 +11,9 @@ use core::{
 mod iter;
 pub use self::iter::{Chords, Intervals, Iter};
 
+mod quality;
+pub use self::quality::{ChordExtension, ChordFormat, ChordQuality, ChordType};
+
 /*
 /// ```
 /// use staff::{chord, midi, Pitch, Chord};
@@ -36,6 +39,9 @@ pub struct Chord {
     pub bass: Option<MidiNote>,
     pub is_inversion: bool,
     pub intervals: IntervalSet,
+    /// Intervals that [`Chord::played_notes`] may drop before a required
+    /// tone when the instrument has fewer strings than the chord has notes.
+    pub optional: IntervalSet,
 }
 
 impl Chord {
@@ -45,6 +51,7 @@ impl Chord {
             bass: None,
             is_inversion: false,
             intervals: IntervalSet::default(),
+            optional: IntervalSet::default(),
         }
     }
 
@@ -63,8 +70,15 @@ impl Chord {
         self
     }
 
+    /// Add `interval`, marking it as optional: droppable by
+    /// [`Chord::played_notes`] before a required tone.
+    pub fn optional_interval(mut self, interval: Interval) -> Self {
+        self.optional.push(interval);
+        self.interval(interval)
+    }
+
     pub fn root(self) -> Self {
-        self.interval(Interval::UNISON)
+        self.optional_interval(Interval::UNISON)
     }
 
     /// ```
@@ -88,14 +102,14 @@ impl Chord {
         Self::new(root)
             .root()
             .interval(Interval::MAJOR_THIRD)
-            .interval(Interval::PERFECT_FIFTH)
+            .optional_interval(Interval::PERFECT_FIFTH)
     }
 
     pub fn minor(root: MidiNote) -> Self {
         Self::new(root)
             .root()
             .interval(Interval::MINOR_THIRD)
-            .interval(Interval::PERFECT_FIFTH)
+            .optional_interval(Interval::PERFECT_FIFTH)
     }
 
     pub fn seventh(root: MidiNote) -> Self {
@@ -153,15 +167,12 @@ impl Chord {
         let lowest_note = bass.unwrap_or(root);
         intervals.extend(iter.map(|midi| midi - lowest_note));
 
-        for i in intervals.clone().into_iter() {
-            dbg!(i);
-        }
-
         Some(Self {
             root,
             bass,
             is_inversion,
             intervals,
+            optional: IntervalSet::default(),
         })
     }
 
@@ -174,18 +185,65 @@ impl Chord {
         self.intervals
             .map(|interval| {
                 let midi_note = self.bass.unwrap_or(self.root) + interval;
-                dbg!(midi_note);
                 midi_note.abs_diff(self.root)
             })
             .collect()
     }
 
+    /// Classify this chord's quality and extension, e.g. minor-seventh or
+    /// sus4.
+    pub fn chord_type(&self) -> ChordType {
+        ChordType::analyze(&self.clone().intervals())
+    }
+
     pub fn midi_notes(self) -> MidiNotes {
         MidiNotes {
             root: self.bass.unwrap_or(self.root),
             intervals: self.intervals,
         }
     }
+
+    /// The notes of this chord, limited to at most `string_count`.
+    ///
+    /// When the chord has more notes than the instrument has strings,
+    /// optional tones are dropped first, preferring to drop the perfect
+    /// fifth, then the root, before any other optional interval. If the
+    /// required tones alone still outnumber the strings, the highest notes
+    /// are dropped.
+    pub fn played_notes(self, string_count: usize) -> impl Iterator<Item = MidiNote> {
+        let optional = self.optional.clone();
+        let root = self.bass.unwrap_or(self.root);
+
+        let mut notes: Vec<(Interval, MidiNote)> = self
+            .intervals
+            .map(|interval| (interval, root + interval))
+            .collect();
+
+        while notes.len() > string_count {
+            let droppable = notes
+                .iter()
+                .enumerate()
+                .filter(|(_, (interval, _))| optional.clone().contains(*interval))
+                .max_by_key(|(_, (interval, _))| match *interval {
+                    Interval::PERFECT_FIFTH => 2,
+                    Interval::UNISON => 1,
+                    _ => 0,
+                })
+                .map(|(index, _)| index);
+
+            match droppable {
+                Some(index) => {
+                    notes.remove(index);
+                }
+                None => {
+                    notes.truncate(string_count);
+                    break;
+                }
+            }
+        }
+
+        notes.into_iter().map(|(_, note)| note)
+    }
 }
 
 pub struct MidiNotes {
@@ -217,7 +275,7 @@ impl IntoIterator for Chord {
 
     fn into_iter(self) -> Self::IntoIter {
         Iter {
-            root: dbg!(self.bass.unwrap_or(self.root)),
+            root: self.bass.unwrap_or(self.root),
             intervals: self.intervals,
         }
     }
@@ -227,38 +285,19 @@ impl fmt::Display for Chord {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.root.fmt(f)?;
 
-        dbg!("{:?}", self.clone().intervals().collect::<Vec<_>>());
-        dbg!(
-            "{:?}",
-            self.clone().intervals().contains(Interval::PERFECT_FOURTH)
-        );
-
-        if self.clone().intervals().contains(Interval::MINOR_THIRD) {
-            f.write_char('m')?
-        } else if self.clone().intervals().contains(Interval::MAJOR_SECOND) {
-            f.write_str("sus2")?
-        } else if self.clone().intervals().contains(Interval::PERFECT_FOURTH) {
-            f.write_str("sus4")?
-        }
-
-        let mut has_fifth = true;
-        if self.clone().intervals().contains(Interval::TRITONE) {
-            f.write_str("b5")?
-        } else if !self.clone().intervals().contains(Interval::PERFECT_FIFTH) {
-            has_fifth = false;
-        }
+        let sounding_intervals = self.clone().intervals();
+        let chord_type = ChordType::analyze(&sounding_intervals);
+        chord_type.write(ChordFormat::Short, f)?;
 
-        if self.clone().intervals().contains(Interval::MINOR_SEVENTH) {
-            f.write_char('7')?
-        } else if self.clone().intervals().contains(Interval::MAJOR_SEVENTH) {
-            f.write_str("maj7")?
-        }
+        let has_fifth = sounding_intervals.clone().contains(Interval::PERFECT_FIFTH)
+            || sounding_intervals.clone().contains(Interval::TRITONE)
+            || sounding_intervals.clone().contains(Interval::MINOR_SIXTH);
 
         if let Some(bass) = self.bass {
             write!(f, "/{}", bass)?;
         }
 
-        if !self.clone().intervals().contains(Interval::UNISON) {
+        if !sounding_intervals.contains(Interval::UNISON) {
             f.write_str("(no root)")?
         }
 
@@ -300,37 +339,112 @@ impl FromStr for Chord {
             _ => natural.into(),
         };
 
-        let mut chord = match next {
-            Some('m') => {
-                next = chars.next();
-                Chord::minor(MidiNote::new(root, Octave::FOUR))
+        let rest: String = next.into_iter().chain(chars).collect();
+
+        let mut quality = None;
+        let mut extension = ChordExtension::None;
+        let mut flat_five = false;
+        let mut remaining = rest.as_str();
+
+        'tokens: loop {
+            for (token, kind) in TOKENS {
+                if let Some(stripped) = remaining.strip_prefix(token) {
+                    match *kind {
+                        Token::Quality(q) => quality = Some(q),
+                        Token::Extension(e) => extension = e,
+                        Token::FlatFive => flat_five = true,
+                    }
+                    remaining = stripped;
+                    continue 'tokens;
+                }
             }
-            _ => Chord::major(MidiNote::new(root, Octave::FOUR)),
+            break;
+        }
+
+        let quality = quality.unwrap_or(ChordQuality::Major);
+        let mut chord = Chord::new(MidiNote::new(root, Octave::FOUR)).root();
+        chord = match quality {
+            ChordQuality::Major => chord
+                .interval(Interval::MAJOR_THIRD)
+                .optional_interval(Interval::PERFECT_FIFTH),
+            ChordQuality::Minor => chord
+                .interval(Interval::MINOR_THIRD)
+                .optional_interval(Interval::PERFECT_FIFTH),
+            ChordQuality::Augmented => chord
+                .interval(Interval::MAJOR_THIRD)
+                .interval(Interval::MINOR_SIXTH),
+            ChordQuality::Diminished => chord
+                .interval(Interval::MINOR_THIRD)
+                .interval(Interval::TRITONE),
+            ChordQuality::DiminishedSeventh => chord
+                .interval(Interval::MINOR_THIRD)
+                .interval(Interval::TRITONE)
+                .interval(Interval::MAJOR_SIXTH),
+            ChordQuality::Sus2 => chord
+                .interval(Interval::MAJOR_SECOND)
+                .optional_interval(Interval::PERFECT_FIFTH),
+            ChordQuality::Sus4 => chord
+                .interval(Interval::PERFECT_FOURTH)
+                .optional_interval(Interval::PERFECT_FIFTH),
+            ChordQuality::Power => chord.interval(Interval::PERFECT_FIFTH),
         };
 
-        loop {
-            if let Some(c) = next {
-                match c {
-                    'b' => match chars.next() {
-                        Some(c) => match c {
-                            '5' => chord.intervals.push(Interval::TRITONE),
-                            _ => todo!(),
-                        },
-                        None => break,
-                    },
-                    '7' => chord.intervals.push(Interval::MINOR_SEVENTH),
-                    _ => todo!(),
-                }
-                next = chars.next();
-            } else {
-                break;
-            }
+        if flat_five
+            && !matches!(
+                quality,
+                ChordQuality::Diminished | ChordQuality::DiminishedSeventh
+            )
+        {
+            chord = chord.interval(Interval::TRITONE);
         }
 
+        chord = match extension {
+            ChordExtension::None => chord,
+            ChordExtension::Sixth => chord.interval(Interval::MAJOR_SIXTH),
+            ChordExtension::Seventh => chord.interval(Interval::MINOR_SEVENTH),
+            ChordExtension::MajorSeventh => chord.interval(Interval::MAJOR_SEVENTH),
+            ChordExtension::Ninth => chord
+                .interval(Interval::MINOR_SEVENTH)
+                .interval(Interval::MAJOR_NINTH),
+            ChordExtension::MajorNinth => chord
+                .interval(Interval::MAJOR_SEVENTH)
+                .interval(Interval::MAJOR_NINTH),
+        };
+
         Ok(chord)
     }
 }
 
+#[derive(Clone, Copy)]
+enum Token {
+    Quality(ChordQuality),
+    Extension(ChordExtension),
+    FlatFive,
+}
+
+/// Recognized chord-symbol tokens, ordered so that longer tokens are tried
+/// before any of their prefixes (e.g. `"maj7"` before `"m"`).
+const TOKENS: &[(&str, Token)] = &[
+    ("maj9", Token::Extension(ChordExtension::MajorNinth)),
+    ("maj7", Token::Extension(ChordExtension::MajorSeventh)),
+    ("dim7", Token::Quality(ChordQuality::DiminishedSeventh)),
+    ("sus2", Token::Quality(ChordQuality::Sus2)),
+    ("sus4", Token::Quality(ChordQuality::Sus4)),
+    ("min", Token::Quality(ChordQuality::Minor)),
+    ("dim", Token::Quality(ChordQuality::Diminished)),
+    ("aug", Token::Quality(ChordQuality::Augmented)),
+    ("°7", Token::Quality(ChordQuality::DiminishedSeventh)),
+    ("b5", Token::FlatFive),
+    ("m", Token::Quality(ChordQuality::Minor)),
+    ("9", Token::Extension(ChordExtension::Ninth)),
+    ("7", Token::Extension(ChordExtension::Seventh)),
+    ("6", Token::Extension(ChordExtension::Sixth)),
+    ("-", Token::Quality(ChordQuality::Minor)),
+    ("+", Token::Quality(ChordQuality::Augmented)),
+    ("°", Token::Quality(ChordQuality::Diminished)),
+    ("5", Token::Quality(ChordQuality::Power)),
+];
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -353,6 +467,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_round_trips_a_power_chord() {
+        let chord: Chord = "C5".parse().unwrap();
+        assert_eq!(chord.to_string(), "C5");
+    }
+
+    #[test]
+    fn it_parses_sus2_and_dim7() {
+        let sus2: Chord = "Csus2".parse().unwrap();
+        assert_eq!(sus2.to_string(), "Csus2");
+
+        let dim7: Chord = "Cdim7".parse().unwrap();
+        assert_eq!(dim7.to_string(), "Cdim7");
+    }
+
+    #[test]
+    fn it_displays_a_major_ninth_chord() {
+        let chord = Chord::major(MidiNote::new(Pitch::C, Octave::FOUR))
+            .major_seventh()
+            .major_ninth();
+
+        assert_eq!(chord.to_string(), "Cmaj9");
+    }
+
+    #[test]
+    fn it_drops_the_fifth_before_the_root_when_short_on_strings() {
+        let chord = Chord::major(MidiNote::new(Pitch::C, Octave::FOUR));
+
+        let four_strings: Vec<_> = chord.clone().played_notes(4).collect();
+        assert_eq!(four_strings.len(), 3);
+
+        let two_strings: Vec<_> = chord.played_notes(2).collect();
+        assert_eq!(
+            two_strings,
+            [
+                MidiNote::new(Pitch::C, Octave::FOUR),
+                MidiNote::new(Pitch::E, Octave::FOUR),
+            ]
+        );
+    }
+
     #[test]
     fn f() {
         let chord = Chord::from_midi(
@@ -0,0 +1,201 @@
+use crate::{set::IntervalSet, Interval};
+use core::fmt::{self, Write};
+
+/// How a [`ChordType`] should spell its quality and extensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChordFormat {
+    /// `min`, `maj7`, `sus2`.
+    Long,
+    /// `m`, `maj7`, `sus2`.
+    Short,
+    /// `-`, `Δ`, `sus2`.
+    Symbolic,
+}
+
+/// The triad (or triad-like) quality of a chord.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChordQuality {
+    Major,
+    Minor,
+    Augmented,
+    Diminished,
+    DiminishedSeventh,
+    Sus2,
+    Sus4,
+    /// No third, e.g. a "power chord".
+    Power,
+}
+
+impl ChordQuality {
+    fn write(self, format: ChordFormat, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ChordFormat::*;
+        use ChordQuality::*;
+
+        match (self, format) {
+            (Major, _) => Ok(()),
+            (Minor, Long) => f.write_str("min"),
+            (Minor, Short) => f.write_char('m'),
+            (Minor, Symbolic) => f.write_char('-'),
+            (Augmented, Symbolic) => f.write_char('+'),
+            (Augmented, _) => f.write_str("aug"),
+            (DiminishedSeventh, Symbolic) => f.write_str("°7"),
+            (DiminishedSeventh, _) => f.write_str("dim7"),
+            (Diminished, Symbolic) => f.write_char('°'),
+            (Diminished, _) => f.write_str("dim"),
+            (Sus2, _) => f.write_str("sus2"),
+            (Sus4, _) => f.write_str("sus4"),
+            (Power, _) => f.write_char('5'),
+        }
+    }
+}
+
+/// An extension stacked on top of a [`ChordQuality`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChordExtension {
+    None,
+    /// Added major sixth, e.g. `C6`.
+    Sixth,
+    /// Minor (dominant) seventh, e.g. `C7`.
+    Seventh,
+    /// Major seventh, e.g. `Cmaj7`.
+    MajorSeventh,
+    /// Minor seventh with an added ninth, e.g. `C9`.
+    Ninth,
+    /// Major seventh with an added ninth, e.g. `Cmaj9`.
+    MajorNinth,
+}
+
+impl ChordExtension {
+    fn write(self, format: ChordFormat, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use ChordExtension::*;
+        use ChordFormat::*;
+
+        match (self, format) {
+            (None, _) => Ok(()),
+            (Sixth, _) => f.write_char('6'),
+            (Seventh, _) => f.write_char('7'),
+            (MajorSeventh, Symbolic) => f.write_char('Δ'),
+            (MajorSeventh, _) => f.write_str("maj7"),
+            (Ninth, _) => f.write_char('9'),
+            (MajorNinth, Symbolic) => f.write_str("Δ9"),
+            (MajorNinth, _) => f.write_str("maj9"),
+        }
+    }
+}
+
+/// The classified quality and extension of a [`Chord`](super::Chord),
+/// derived from its [`IntervalSet`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChordType {
+    pub quality: ChordQuality,
+    pub extension: ChordExtension,
+}
+
+impl ChordType {
+    /// Classify an [`IntervalSet`] into a named quality and extension.
+    pub fn analyze(intervals: &IntervalSet) -> Self {
+        let has = |interval: Interval| intervals.clone().contains(interval);
+
+        let has_minor_third = has(Interval::MINOR_THIRD);
+        let has_major_third = has(Interval::MAJOR_THIRD);
+        let has_sharp_five = has(Interval::MINOR_SIXTH);
+        let has_flat_five = has(Interval::TRITONE);
+        let has_sixth = has(Interval::MAJOR_SIXTH);
+
+        let quality = if has_minor_third && has_flat_five {
+            if has_sixth {
+                ChordQuality::DiminishedSeventh
+            } else {
+                ChordQuality::Diminished
+            }
+        } else if has_major_third && has_sharp_five {
+            ChordQuality::Augmented
+        } else if has_minor_third {
+            ChordQuality::Minor
+        } else if has_major_third {
+            ChordQuality::Major
+        } else if has(Interval::MAJOR_SECOND) {
+            ChordQuality::Sus2
+        } else if has(Interval::PERFECT_FOURTH) {
+            ChordQuality::Sus4
+        } else {
+            ChordQuality::Power
+        };
+
+        let has_ninth = has(Interval::MAJOR_NINTH);
+        let extension = if has(Interval::MAJOR_SEVENTH) {
+            if has_ninth {
+                ChordExtension::MajorNinth
+            } else {
+                ChordExtension::MajorSeventh
+            }
+        } else if has(Interval::MINOR_SEVENTH) {
+            if has_ninth {
+                ChordExtension::Ninth
+            } else {
+                ChordExtension::Seventh
+            }
+        } else if has_sixth && !matches!(quality, ChordQuality::DiminishedSeventh) {
+            ChordExtension::Sixth
+        } else {
+            ChordExtension::None
+        };
+
+        Self { quality, extension }
+    }
+
+    /// Format this chord type using `format`, e.g. `min`, `m`, or `-` for a
+    /// plain minor triad.
+    pub fn write(self, format: ChordFormat, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.quality.write(format, f)?;
+        self.extension.write(format, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Interval;
+
+    fn intervals(values: impl IntoIterator<Item = Interval>) -> IntervalSet {
+        values.into_iter().collect()
+    }
+
+    #[test]
+    fn it_classifies_a_major_seventh_chord() {
+        let chord_type = ChordType::analyze(&intervals([
+            Interval::UNISON,
+            Interval::MAJOR_THIRD,
+            Interval::PERFECT_FIFTH,
+            Interval::MAJOR_SEVENTH,
+        ]));
+
+        assert_eq!(chord_type.quality, ChordQuality::Major);
+        assert_eq!(chord_type.extension, ChordExtension::MajorSeventh);
+    }
+
+    #[test]
+    fn it_classifies_a_diminished_seventh_chord() {
+        let chord_type = ChordType::analyze(&intervals([
+            Interval::UNISON,
+            Interval::MINOR_THIRD,
+            Interval::TRITONE,
+            Interval::MAJOR_SIXTH,
+        ]));
+
+        assert_eq!(chord_type.quality, ChordQuality::DiminishedSeventh);
+    }
+
+    #[test]
+    fn it_classifies_a_power_chord() {
+        let chord_type =
+            ChordType::analyze(&intervals([Interval::UNISON, Interval::PERFECT_FIFTH]));
+
+        assert_eq!(chord_type.quality, ChordQuality::Power);
+        assert_eq!(chord_type.extension, ChordExtension::None);
+    }
+}
@@ -0,0 +1,234 @@
+//! Voicing engine that arranges a sequence of [`MidiNote`]s onto a stringed
+//! instrument as playable `(string, fret)` positions.
+use crate::midi::MidiNote;
+
+/// Extra cost added to a transition between consecutive positions for each
+/// of the two that falls on an open string.
+const OPEN_STRING_PENALTY: f64 = 8.0;
+
+/// A stringed instrument's tuning, given as the open-string pitch of each
+/// string from lowest to highest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tuning {
+    open_strings: Vec<MidiNote>,
+}
+
+impl Tuning {
+    /// Create a tuning from the open-string pitches, ordered lowest to highest.
+    pub fn new(open_strings: Vec<MidiNote>) -> Self {
+        Self { open_strings }
+    }
+
+    /// Standard 6-string guitar tuning (E2 A2 D3 G3 B3 E4).
+    pub fn guitar() -> Self {
+        Self::new(
+            [40, 45, 50, 55, 59, 64]
+                .into_iter()
+                .map(MidiNote::from_byte)
+                .collect(),
+        )
+    }
+
+    /// Standard 4-string bass tuning (E1 A1 D2 G2).
+    pub fn bass() -> Self {
+        Self::new(
+            [28, 33, 38, 43]
+                .into_iter()
+                .map(MidiNote::from_byte)
+                .collect(),
+        )
+    }
+
+    /// The number of strings in this tuning.
+    pub fn string_count(&self) -> usize {
+        self.open_strings.len()
+    }
+
+    /// The open-string pitch for `string`, counting up from the lowest string.
+    pub fn open_string(&self, string: u8) -> MidiNote {
+        self.open_strings[string as usize]
+    }
+}
+
+/// A playable position on a [`Fretboard`]: the `string`-th string (`0` is the
+/// lowest) fretted at `fret` (`0` is open).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Position {
+    pub string: u8,
+    pub fret: u8,
+}
+
+impl Position {
+    pub fn new(string: u8, fret: u8) -> Self {
+        Self { string, fret }
+    }
+
+    fn transition_cost(self, next: Self) -> f64 {
+        let fret_delta = (self.fret as f64 - next.fret as f64).abs();
+        let string_delta = (self.string as f64 - next.string as f64).abs();
+        let mut cost = fret_delta
+            + 0.3 * string_delta
+            + 0.3 * (self.fret as f64 + next.fret as f64)
+            + 0.5 * (self.string as f64 + next.string as f64);
+
+        if self.fret == 0 {
+            cost += OPEN_STRING_PENALTY;
+        }
+        if next.fret == 0 {
+            cost += OPEN_STRING_PENALTY;
+        }
+
+        cost
+    }
+}
+
+/// A stringed instrument: a [`Tuning`] with a fixed number of frets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fretboard {
+    tuning: Tuning,
+    fret_count: u8,
+}
+
+impl Fretboard {
+    pub fn new(tuning: Tuning, fret_count: u8) -> Self {
+        Self { tuning, fret_count }
+    }
+
+    /// Every `(string, fret)` position that produces `note`.
+    pub fn positions_for(&self, note: MidiNote) -> Vec<Position> {
+        (0..self.tuning.string_count() as u8)
+            .filter_map(|string| {
+                let open = self.tuning.open_string(string);
+                if u8::from(note) < u8::from(open) {
+                    return None;
+                }
+
+                let fret = u8::from(note) - u8::from(open);
+                (fret <= self.fret_count).then(|| Position::new(string, fret))
+            })
+            .collect()
+    }
+
+    /// Arrange `notes` onto the fretboard, minimizing the total biomechanical
+    /// cost of moving between consecutive positions.
+    ///
+    /// Returns `None` if any note has no playable position on this instrument.
+    pub fn voice<I>(&self, notes: I) -> Option<Vec<Position>>
+    where
+        I: IntoIterator<Item = MidiNote>,
+    {
+        let candidates: Vec<Vec<Position>> = notes
+            .into_iter()
+            .map(|note| self.positions_for(note))
+            .collect();
+
+        if candidates.iter().any(Vec::is_empty) {
+            return None;
+        }
+
+        // `costs[i]` holds the cheapest cumulative cost to reach each
+        // candidate position of note `i`, alongside the index of the
+        // candidate of note `i - 1` it backtracks to.
+        let mut costs: Vec<Vec<(f64, Option<usize>)>> = Vec::with_capacity(candidates.len());
+
+        for (i, positions) in candidates.iter().enumerate() {
+            let row = if i == 0 {
+                positions
+                    .iter()
+                    .map(|position| (Self::unary_cost(*position), None))
+                    .collect()
+            } else {
+                let prev_row = &costs[i - 1];
+                let prev_positions = &candidates[i - 1];
+
+                positions
+                    .iter()
+                    .map(|&position| {
+                        prev_positions
+                            .iter()
+                            .zip(prev_row.iter())
+                            .enumerate()
+                            .map(|(prev_index, (&prev_position, &(prev_cost, _)))| {
+                                let cost = prev_cost
+                                    + prev_position.transition_cost(position)
+                                    + Self::unary_cost(position);
+                                (cost, Some(prev_index))
+                            })
+                            .min_by(|(a, _), (b, _)| a.total_cmp(b))
+                            .unwrap()
+                    })
+                    .collect()
+            };
+            costs.push(row);
+        }
+
+        let last_row = costs.last()?;
+        let (mut index, _) = last_row
+            .iter()
+            .enumerate()
+            .min_by(|(_, (a, _)), (_, (b, _))| a.total_cmp(b))?;
+
+        let mut path = Vec::with_capacity(candidates.len());
+        for i in (0..candidates.len()).rev() {
+            path.push(candidates[i][index]);
+            if let Some(back) = costs[i][index].1 {
+                index = back;
+            }
+        }
+        path.reverse();
+
+        Some(path)
+    }
+
+    /// Per-note cost of a position in isolation, penalizing high frets and
+    /// high strings.
+    fn unary_cost(position: Position) -> f64 {
+        0.3 * position.fret as f64 + 0.5 * position.string as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::Octave;
+    use crate::Pitch;
+
+    #[test]
+    fn it_voices_a_c_major_triad_on_guitar() {
+        let fretboard = Fretboard::new(Tuning::guitar(), 20);
+
+        let notes = [
+            MidiNote::new(Pitch::C, Octave::FOUR),
+            MidiNote::new(Pitch::E, Octave::FOUR),
+            MidiNote::new(Pitch::G, Octave::FOUR),
+        ];
+
+        let path = fretboard.voice(notes).unwrap();
+        assert_eq!(path.len(), notes.len());
+
+        for (position, note) in path.iter().zip(notes) {
+            let open = fretboard.tuning.open_string(position.string);
+            assert_eq!(open + crate::Interval::new(position.fret), note);
+        }
+    }
+
+    #[test]
+    fn it_returns_none_for_an_unplayable_note() {
+        let fretboard = Fretboard::new(Tuning::guitar(), 2);
+        let notes = [MidiNote::new(Pitch::C, Octave::TWO)];
+
+        assert_eq!(fretboard.voice(notes), None);
+    }
+
+    #[test]
+    fn it_has_no_positions_below_the_lowest_open_string() {
+        let fretboard = Fretboard::new(Tuning::guitar(), 20);
+        let note = MidiNote::new(Pitch::C, Octave::ONE);
+
+        assert!(fretboard.positions_for(note).is_empty());
+        assert_eq!(fretboard.voice([note]), None);
+    }
+}
@@ -30,6 +30,8 @@ impl Interval {
     pub const MINOR_SEVENTH: Self = Self::new(10);
     pub const MAJOR_SEVENTH: Self = Self::new(11);
 
+    pub const MAJOR_NINTH: Self = Self::new(14);
+
     pub const THIRTEENTH: Self = Self::new(21);
 
     pub const fn new(semitones: u8) -> Self {
@@ -65,10 +67,20 @@ impl fmt::Display for Interval {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
             Interval::UNISON => f.write_char('1'),
+            Interval::MINOR_SECOND => f.write_str("m2"),
+            Interval::MAJOR_SECOND => f.write_char('2'),
+            Interval::MINOR_THIRD => f.write_str("m3"),
             Interval::MAJOR_THIRD => f.write_char('3'),
+            Interval::PERFECT_FOURTH => f.write_char('4'),
+            Interval::TRITONE => f.write_str("b5"),
             Interval::PERFECT_FIFTH => f.write_char('5'),
+            Interval::MINOR_SIXTH => f.write_str("m6"),
+            Interval::MAJOR_SIXTH => f.write_char('6'),
             Interval::MINOR_SEVENTH => f.write_str("m7"),
-            _ => todo!(),
+            Interval::MAJOR_SEVENTH => f.write_str("maj7"),
+            Interval::MAJOR_NINTH => f.write_char('9'),
+            Interval::THIRTEENTH => f.write_str("13"),
+            _ => write!(f, "{}", self.semitones),
         }
     }
 }
@@ -0,0 +1,147 @@
+use core::iter::Peekable;
+use core::str::Chars;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Token {
+    /// `{`
+    MeasureOpen,
+    /// `}`
+    MeasureClose,
+    /// `(`
+    GroupOpen,
+    /// `)*N`
+    GroupClose(u32),
+    /// A note letter `a`-`g`, its accidental run (`+`/`-`), and an optional
+    /// duration suffix.
+    Note {
+        letter: char,
+        accidentals: i8,
+        duration: Option<Duration>,
+    },
+    /// `oN`
+    OctaveSet(u8),
+    /// `<` (down an octave) or `>` (up an octave)
+    OctaveShift(i8),
+    /// `rN`
+    Rest(Option<Duration>),
+    /// `/`, joining notes of a chord.
+    ChordJoin,
+    /// `|`, a barline. Purely visual; carries no meaning to the parser.
+    Bar,
+    /// `!bN`, a tempo instruction in beats per minute.
+    Tempo(u32),
+    /// `!vN`, a volume instruction.
+    Volume(u32),
+}
+
+/// A note value (`4` = quarter, `8` = eighth, ...) with a dotted-note count.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Duration {
+    pub value: u8,
+    pub dots: u8,
+}
+
+pub struct Lexer<'a> {
+    chars: Peekable<Chars<'a>>,
+}
+
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            chars: input.chars().peekable(),
+        }
+    }
+
+    fn read_u32(&mut self) -> u32 {
+        let mut value = 0u32;
+        while let Some(c) = self.chars.peek().copied() {
+            if let Some(digit) = c.to_digit(10) {
+                value = value * 10 + digit;
+                self.chars.next();
+            } else {
+                break;
+            }
+        }
+        value
+    }
+
+    fn read_duration(&mut self) -> Option<Duration> {
+        if !matches!(self.chars.peek(), Some(c) if c.is_ascii_digit()) {
+            return None;
+        }
+
+        let value = self.read_u32() as u8;
+        let mut dots = 0;
+        while self.chars.peek() == Some(&'.') {
+            self.chars.next();
+            dots += 1;
+        }
+
+        Some(Duration { value, dots })
+    }
+}
+
+impl<'a> Iterator for Lexer<'a> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let c = *self.chars.peek()?;
+            if c.is_whitespace() {
+                self.chars.next();
+                continue;
+            }
+            break;
+        }
+
+        let c = self.chars.next()?;
+        Some(match c {
+            '{' => Token::MeasureOpen,
+            '}' => Token::MeasureClose,
+            '(' => Token::GroupOpen,
+            ')' => {
+                let count = if self.chars.peek() == Some(&'*') {
+                    self.chars.next();
+                    self.read_u32()
+                } else {
+                    1
+                };
+                Token::GroupClose(count)
+            }
+            '/' => Token::ChordJoin,
+            '|' => Token::Bar,
+            '<' => Token::OctaveShift(-1),
+            '>' => Token::OctaveShift(1),
+            'o' => Token::OctaveSet(self.read_u32() as u8),
+            'r' => Token::Rest(self.read_duration()),
+            '!' => match self.chars.next() {
+                Some('b') => Token::Tempo(self.read_u32()),
+                Some('v') => Token::Volume(self.read_u32()),
+                _ => return self.next(),
+            },
+            'a'..='g' => {
+                let mut accidentals = 0i8;
+                loop {
+                    match self.chars.peek() {
+                        Some('+') => {
+                            accidentals += 1;
+                            self.chars.next();
+                        }
+                        Some('-') => {
+                            accidentals -= 1;
+                            self.chars.next();
+                        }
+                        _ => break,
+                    }
+                }
+                let duration = self.read_duration();
+                Token::Note {
+                    letter: c,
+                    accidentals,
+                    duration,
+                }
+            }
+            _ => return self.next(),
+        })
+    }
+}
@@ -0,0 +1,340 @@
+//! A compact plain-text notation for authoring scores without constructing
+//! [`MeasureItem`]s by hand.
+//!
+//! ```text
+//! { c4 e g | b4. r8 } { (a/c/e4)*2 } !b120
+//! ```
+//!
+//! - Notes are a letter `a`-`g` with trailing `+`/`-` accidentals.
+//! - `oN` sets the current octave; `<`/`>` shift it down/up by one.
+//! - Durations are an integer with optional dotted (`.`) suffixes and stick
+//!   until the next note or rest that specifies its own.
+//! - `rN` is a rest, `tone/tone/tone` is a chord.
+//! - `|` is a barline; it is purely visual and carries no meaning to the
+//!   parser.
+//! - A `(...)` group suffixed with `*N` is repeated `N` times.
+//! - `{...}` blocks become individual [`Measure`]s.
+//! - `!bN` and `!vN` attach a tempo (bpm) and volume to the enclosing
+//!   measure, or to the most recently closed measure when they appear
+//!   after it. The `!` is required: a bare `bN` is indistinguishable from
+//!   note `b` followed by a duration.
+use crate::{
+    midi::{MidiNote, Octave},
+    render::{measure::MeasureItem, Measure, Renderer},
+    Duration, Natural, Note, Pitch,
+};
+
+mod lexer;
+use self::lexer::{Lexer, Token};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    InvalidNote(char),
+    InvalidOctave(u8),
+    UnmatchedGroupOpen,
+    UnmatchedGroupClose,
+    UnmatchedMeasureOpen,
+    ExpectedNote,
+}
+
+/// A single note, chord, or rest parsed from the notation, with its
+/// duration resolved.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Event {
+    Note(MidiNote, lexer::Duration),
+    Chord(Vec<MidiNote>, lexer::Duration),
+    Rest(lexer::Duration),
+}
+
+/// One parsed `{...}` block (or the whole input, if it contains no blocks),
+/// ready to be turned into a [`Measure`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ParsedMeasure {
+    pub events: Vec<Event>,
+    pub tempo: Option<u32>,
+    pub volume: Option<u32>,
+}
+
+/// Parse the notation into [`ParsedMeasure`]s.
+pub fn parse(input: &str) -> Result<Vec<ParsedMeasure>, ParseError> {
+    let tokens: Vec<Token> = Lexer::new(input).collect();
+
+    if !tokens.iter().any(|token| *token == Token::MeasureOpen) {
+        let mut state = State::default();
+        let events = parse_units(&tokens, &mut state)?;
+        return Ok(vec![ParsedMeasure {
+            events,
+            tempo: state.tempo,
+            volume: state.volume,
+        }]);
+    }
+
+    let mut state = State::default();
+    let mut measures = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if tokens[i] == Token::MeasureOpen {
+            let close = matching_close(
+                &tokens,
+                i,
+                |token| *token == Token::MeasureOpen,
+                |token| *token == Token::MeasureClose,
+            )
+            .ok_or(ParseError::UnmatchedMeasureOpen)?;
+
+            state.tempo = None;
+            state.volume = None;
+            let events = parse_units(&tokens[i + 1..close], &mut state)?;
+            measures.push(ParsedMeasure {
+                events,
+                tempo: state.tempo,
+                volume: state.volume,
+            });
+            i = close + 1;
+        } else {
+            // A tempo/volume instruction after a `}` attaches to the measure
+            // it just closed; any other stray token is ignored.
+            match &tokens[i] {
+                Token::Tempo(bpm) => {
+                    if let Some(last) = measures.last_mut() {
+                        last.tempo = Some(*bpm);
+                    }
+                }
+                Token::Volume(volume) => {
+                    if let Some(last) = measures.last_mut() {
+                        last.volume = Some(*volume);
+                    }
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+
+    Ok(measures)
+}
+
+/// Parse the notation and lay it out as [`Measure`]s ready to render.
+pub fn measures<'r>(input: &str, renderer: &'r Renderer) -> Result<Vec<Measure<'r>>, ParseError> {
+    Ok(parse(input)?
+        .into_iter()
+        .map(|measure| {
+            let items = measure
+                .events
+                .into_iter()
+                .map(|event| match event {
+                    Event::Note(note, duration) => {
+                        MeasureItem::note(note, to_duration(duration), renderer)
+                    }
+                    Event::Chord(notes, duration) => {
+                        MeasureItem::chord(notes, to_duration(duration), renderer)
+                    }
+                    Event::Rest(duration) => MeasureItem::rest(to_duration(duration), renderer),
+                })
+                .collect();
+            Measure::new(items, renderer)
+        })
+        .collect())
+}
+
+#[derive(Clone, Copy, Debug)]
+struct State {
+    octave: u8,
+    duration: lexer::Duration,
+    tempo: Option<u32>,
+    volume: Option<u32>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            octave: 4,
+            duration: lexer::Duration { value: 4, dots: 0 },
+            tempo: None,
+            volume: None,
+        }
+    }
+}
+
+impl State {
+    fn resolve_note(&self, letter: char, accidentals: i8) -> Result<MidiNote, ParseError> {
+        let natural: Natural = letter.try_into().map_err(|_| ParseError::InvalidNote(letter))?;
+        let note: Note = match accidentals {
+            0 => natural.into(),
+            1 => Note::sharp(natural),
+            -1 => Note::flat(natural),
+            2 => Note::double_sharp(natural),
+            -2 => Note::double_flat(natural),
+            _ => return Err(ParseError::InvalidNote(letter)),
+        };
+
+        let pitch: Pitch = note.into();
+        let octave = Octave::new(self.octave).ok_or(ParseError::InvalidOctave(self.octave))?;
+        Ok(MidiNote::new(pitch, octave))
+    }
+}
+
+fn to_duration(duration: lexer::Duration) -> Duration {
+    let mut result = Duration::new(duration.value);
+    for _ in 0..duration.dots {
+        result = result.dot();
+    }
+    result
+}
+
+/// Find the index of the token closing the group or measure opened at
+/// `open`, tracking nested groups/measures of the same kind.
+fn matching_close(tokens: &[Token], open: usize, is_open: fn(&Token) -> bool, is_close: fn(&Token) -> bool) -> Option<usize> {
+    let mut depth = 0;
+    for (offset, token) in tokens[open..].iter().enumerate() {
+        if is_open(token) {
+            depth += 1;
+        } else if is_close(token) {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open + offset);
+            }
+        }
+    }
+    None
+}
+
+fn parse_units(tokens: &[Token], state: &mut State) -> Result<Vec<Event>, ParseError> {
+    let mut events = Vec::new();
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::GroupOpen => {
+                let close = matching_close(
+                    tokens,
+                    i,
+                    |token| *token == Token::GroupOpen,
+                    |token| matches!(token, Token::GroupClose(_)),
+                )
+                .ok_or(ParseError::UnmatchedGroupOpen)?;
+                let repeat = match tokens[close] {
+                    Token::GroupClose(n) => n,
+                    _ => unreachable!(),
+                };
+
+                let inner = parse_units(&tokens[i + 1..close], state)?;
+                for _ in 0..repeat {
+                    events.extend(inner.iter().cloned());
+                }
+                i = close + 1;
+            }
+            Token::Note {
+                letter,
+                accidentals,
+                duration,
+            } => {
+                if let Some(d) = duration {
+                    state.duration = *d;
+                }
+                let mut notes = vec![state.resolve_note(*letter, *accidentals)?];
+
+                let mut j = i + 1;
+                while j + 1 < tokens.len() && tokens[j] == Token::ChordJoin {
+                    match &tokens[j + 1] {
+                        Token::Note {
+                            letter,
+                            accidentals,
+                            duration,
+                        } => {
+                            if let Some(d) = duration {
+                                state.duration = *d;
+                            }
+                            notes.push(state.resolve_note(*letter, *accidentals)?);
+                            j += 2;
+                        }
+                        _ => return Err(ParseError::ExpectedNote),
+                    }
+                }
+
+                events.push(if notes.len() == 1 {
+                    Event::Note(notes[0], state.duration)
+                } else {
+                    Event::Chord(notes, state.duration)
+                });
+                i = j;
+            }
+            Token::Rest(duration) => {
+                if let Some(d) = duration {
+                    state.duration = *d;
+                }
+                events.push(Event::Rest(state.duration));
+                i += 1;
+            }
+            Token::OctaveSet(octave) => {
+                state.octave = *octave;
+                i += 1;
+            }
+            Token::OctaveShift(delta) => {
+                state.octave = (state.octave as i8 + delta).clamp(0, 9) as u8;
+                i += 1;
+            }
+            Token::Tempo(bpm) => {
+                state.tempo = Some(*bpm);
+                i += 1;
+            }
+            Token::Volume(volume) => {
+                state.volume = Some(*volume);
+                i += 1;
+            }
+            Token::ChordJoin | Token::Bar => {
+                i += 1;
+            }
+            Token::GroupClose(_) => return Err(ParseError::UnmatchedGroupClose),
+            Token::MeasureOpen | Token::MeasureClose => i += 1,
+        }
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::midi::Octave;
+
+    #[test]
+    fn it_parses_a_single_measure_of_notes() {
+        let measures = parse("c4 e g").unwrap();
+        assert_eq!(measures.len(), 1);
+        assert_eq!(measures[0].events.len(), 3);
+        assert_eq!(
+            measures[0].events[0],
+            Event::Note(
+                MidiNote::new(Pitch::C, Octave::new(4).unwrap()),
+                lexer::Duration { value: 4, dots: 0 }
+            )
+        );
+    }
+
+    #[test]
+    fn it_parses_a_chord() {
+        let measures = parse("c/e/g4").unwrap();
+        assert_eq!(measures[0].events.len(), 1);
+        assert!(matches!(&measures[0].events[0], Event::Chord(notes, _) if notes.len() == 3));
+    }
+
+    #[test]
+    fn it_expands_a_repeated_group() {
+        let measures = parse("(c4)*3").unwrap();
+        assert_eq!(measures[0].events.len(), 3);
+    }
+
+    #[test]
+    fn it_splits_explicit_measures_and_keeps_instructions() {
+        let measures = parse("{c4}{e4}!b120").unwrap();
+        assert_eq!(measures.len(), 2);
+        assert_eq!(measures[1].tempo, Some(120));
+    }
+
+    #[test]
+    fn it_ignores_barlines() {
+        let measures = parse("c4 e g | b4. r8").unwrap();
+        assert_eq!(measures[0].events.len(), 5);
+    }
+}
@@ -0,0 +1,151 @@
+//! Frequency and microtonal tuning support for [`MidiNote`].
+use crate::midi::MidiNote;
+
+/// The reference pitch a [`Tuning`] is measured against, e.g. A4 = 440 Hz.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConcertPitch {
+    pub reference: MidiNote,
+    pub frequency: f64,
+}
+
+impl ConcertPitch {
+    pub fn new(reference: MidiNote, frequency: f64) -> Self {
+        Self {
+            reference,
+            frequency,
+        }
+    }
+
+    /// A4 = 440 Hz.
+    pub fn a440() -> Self {
+        Self::new(MidiNote::from_byte(69), 440.)
+    }
+}
+
+impl Default for ConcertPitch {
+    fn default() -> Self {
+        Self::a440()
+    }
+}
+
+/// A tuning system used to convert a [`MidiNote`] to cents above a
+/// [`ConcertPitch`]'s reference note.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Tuning {
+    /// Equal temperament with `edo` equal divisions of the octave (12 for
+    /// standard 12-TET).
+    Equal { edo: u16 },
+    /// Arbitrary cents offsets from the reference note, one per scale
+    /// degree, repeating every octave.
+    Custom { cents_per_degree: Vec<f64> },
+}
+
+impl Tuning {
+    /// Standard 12-tone equal temperament.
+    pub fn equal_temperament() -> Self {
+        Self::Equal { edo: 12 }
+    }
+
+    /// Equal temperament with a custom number of equal divisions per octave.
+    pub fn edo(divisions: u16) -> Self {
+        Self::Equal { edo: divisions }
+    }
+
+    /// A non-equal temperament given as cents offsets per scale degree.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cents_per_degree` is empty, since a tuning needs at least
+    /// one degree per octave to resolve a note to a cents offset.
+    pub fn custom(cents_per_degree: Vec<f64>) -> Self {
+        assert!(
+            !cents_per_degree.is_empty(),
+            "Tuning::custom requires at least one degree per octave"
+        );
+        Self::Custom { cents_per_degree }
+    }
+
+    /// Cents above `reference` for the note `midi` semitones from C-1.
+    fn cents(&self, midi: i32, reference: i32) -> f64 {
+        let steps = midi - reference;
+
+        match self {
+            Self::Equal { edo } => steps as f64 * 1200. / *edo as f64,
+            Self::Custom { cents_per_degree } => {
+                let len = cents_per_degree.len() as i32;
+                let degree = steps.rem_euclid(len);
+                let octave = (steps - degree) / len;
+                cents_per_degree[degree as usize] + octave as f64 * 1200.
+            }
+        }
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::equal_temperament()
+    }
+}
+
+/// A frequency ratio between two pitches.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ratio(pub f64);
+
+impl Ratio {
+    /// The size of this ratio in cents.
+    pub fn cents(self) -> f64 {
+        1200. * self.0.log2()
+    }
+}
+
+impl MidiNote {
+    /// This note's frequency in Hz under `tuning`, using the default
+    /// [`ConcertPitch`] (A4 = 440 Hz).
+    pub fn pitch(&self, tuning: &Tuning) -> f64 {
+        self.pitch_at(tuning, ConcertPitch::default())
+    }
+
+    /// This note's frequency in Hz under `tuning`, relative to `concert_pitch`.
+    pub fn pitch_at(&self, tuning: &Tuning, concert_pitch: ConcertPitch) -> f64 {
+        let cents = tuning.cents(u8::from(*self) as i32, u8::from(concert_pitch.reference) as i32);
+        concert_pitch.frequency * 2f64.powf(cents / 1200.)
+    }
+
+    /// The frequency [`Ratio`] from this note to `other` under `tuning`.
+    pub fn ratio_to(&self, other: MidiNote, tuning: &Tuning) -> Ratio {
+        Ratio(other.pitch(tuning) / self.pitch(tuning))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_computes_a440_in_12_tet() {
+        let a4 = MidiNote::from_byte(69);
+        assert_eq!(a4.pitch(&Tuning::equal_temperament()), 440.);
+    }
+
+    #[test]
+    fn it_computes_an_octave_above_a440() {
+        let a5 = MidiNote::from_byte(81);
+        let pitch = a5.pitch(&Tuning::equal_temperament());
+        assert!((pitch - 880.).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_computes_a_24_edo_quarter_tone() {
+        let quarter_tone_above_a4 = MidiNote::from_byte(69).ratio_to(
+            MidiNote::from_byte(70),
+            &Tuning::edo(24),
+        );
+        assert!((quarter_tone_above_a4.cents() - 50.).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one degree")]
+    fn it_rejects_an_empty_custom_tuning() {
+        Tuning::custom(vec![]);
+    }
+}
@@ -1,4 +1,5 @@
 use super::Renderer;
+use crate::Duration;
 use svg::Node;
 
 mod clef;
@@ -14,9 +15,44 @@ pub use self::note_head::NoteHead;
 mod stem;
 pub use stem::Stem;
 
+/// A meter, e.g. `4/4` or `6/8`, carried on a [`Measure`] to drive beat
+/// subdivision, spacing, and barline placement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeSignature {
+    pub numerator: u8,
+    pub denominator: u8,
+}
+
+impl TimeSignature {
+    pub const COMMON: Self = Self::new(4, 4);
+
+    pub const fn new(numerator: u8, denominator: u8) -> Self {
+        Self {
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl Default for TimeSignature {
+    fn default() -> Self {
+        Self::COMMON
+    }
+}
+
+/// Whether a [`Measure`]'s contained durations over- or under-fill its
+/// [`TimeSignature`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillError {
+    Overfull,
+    Underfull,
+}
+
 pub struct Measure<'r> {
     chords: Vec<MeasureItem<'r>>,
     pub width: f64,
+    pub time_signature: TimeSignature,
 }
 
 impl<'r> Measure<'r> {
@@ -25,7 +61,38 @@ impl<'r> Measure<'r> {
             + renderer.padding * 2.
             + renderer.stroke_width * 2.;
 
-        Self { chords, width }
+        Self {
+            chords,
+            width,
+            time_signature: TimeSignature::default(),
+        }
+    }
+
+    /// Set the meter used for beat subdivision, spacing, and barline
+    /// validation.
+    pub fn time_signature(mut self, time_signature: TimeSignature) -> Self {
+        self.time_signature = time_signature;
+        self
+    }
+
+    /// Check the contained items' total duration against this measure's
+    /// [`TimeSignature`], flagging over- or under-full bars.
+    pub fn validate(&self) -> Result<(), FillError> {
+        let beats: f64 = self
+            .chords
+            .iter()
+            .filter_map(item_duration)
+            .map(|duration| duration.beats(self.time_signature.denominator))
+            .sum();
+
+        let expected = self.time_signature.numerator as f64;
+        if beats > expected {
+            Err(FillError::Overfull)
+        } else if beats < expected {
+            Err(FillError::Underfull)
+        } else {
+            Ok(())
+        }
     }
 
     pub fn svg(
@@ -72,30 +139,8 @@ impl<'r> Measure<'r> {
         for chord in &self.chords {
             chord.svg(chord_x, top, renderer, node);
 
-            let duration = match &chord.kind {
-                MeasureItemKind::Chord {
-                    top,
-                    duration,
-                    notes,
-                    is_upside_down,
-                    ledger_lines,
-                    stem,
-                    accidentals,
-                } => Some(duration),
-                MeasureItemKind::Note {
-                    top,
-                    duration,
-                    note,
-                    is_upside_down,
-                    has_ledger_line,
-                    has_stem,
-                    accidental,
-                } => Some(duration),
-                MeasureItemKind::Rest { duration } => Some(duration),
-                _ => None,
-            };
-            if let Some(duration) = duration {
-                chord_x += extra_width / duration.beats(4);
+            if let Some(duration) = item_duration(chord) {
+                chord_x += extra_width / duration.beats(self.time_signature.denominator);
             }
             chord_x += chord.width;
         }
@@ -133,3 +178,13 @@ impl<'r> Measure<'r> {
         );
     }
 }
+
+/// The duration carried by a `Chord`, `Note`, or `Rest` item, if any.
+fn item_duration<'a, 'r>(item: &'a MeasureItem<'r>) -> Option<&'a Duration> {
+    match &item.kind {
+        MeasureItemKind::Chord { duration, .. } => Some(duration),
+        MeasureItemKind::Note { duration, .. } => Some(duration),
+        MeasureItemKind::Rest { duration } => Some(duration),
+        _ => None,
+    }
+}